@@ -23,22 +23,124 @@
 use std::alloc;
 use std::ops::Index;
 use std::ops::IndexMut;
+use std::ptr::NonNull;
 
-pub struct MiniVec<T: Clone> {
+/// The error returned by an [`Allocator`] when it cannot satisfy a request.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AllocError;
+
+/// A source of memory for a [`MiniVec`], modeled on the `Allocator` trait
+/// surfaced by `allocator-api2`.
+///
+/// This is a minimal in-crate stand-in rather than a dependency on the
+/// unstable `std::alloc::Allocator`, so `MiniVec` can be generic over
+/// arena- or bump-backed allocators without relying on nightly.
+pub trait Allocator {
+    /// Allocates a block of memory fitting `layout`.
+    fn allocate(&self, layout: alloc::Layout) -> Result<NonNull<u8>, AllocError>;
+
+    /// Deallocates a block of memory previously returned by `allocate` or
+    /// `grow` on this allocator, with the `layout` it was allocated with.
+    fn deallocate(&self, ptr: NonNull<u8>, layout: alloc::Layout);
+
+    /// Grows a previously allocated block from `old_layout` to `new_layout`,
+    /// possibly moving it.
+    fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: alloc::Layout,
+        new_layout: alloc::Layout,
+    ) -> Result<NonNull<u8>, AllocError>;
+}
+
+/// The default [`Allocator`], delegating directly to `std::alloc`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Global;
+
+impl Allocator for Global {
+    fn allocate(&self, layout: alloc::Layout) -> Result<NonNull<u8>, AllocError> {
+        let ptr = unsafe { alloc::alloc(layout) };
+        NonNull::new(ptr).ok_or(AllocError)
+    }
+
+    fn deallocate(&self, ptr: NonNull<u8>, layout: alloc::Layout) {
+        unsafe { alloc::dealloc(ptr.as_ptr(), layout) }
+    }
+
+    fn grow(
+        &self,
+        ptr: NonNull<u8>,
+        old_layout: alloc::Layout,
+        new_layout: alloc::Layout,
+    ) -> Result<NonNull<u8>, AllocError> {
+        let new_ptr = unsafe { alloc::realloc(ptr.as_ptr(), old_layout, new_layout.size()) };
+        NonNull::new(new_ptr).ok_or(AllocError)
+    }
+}
+
+/// Creates a [`MiniVec`] containing the given elements, analogous to the
+/// standard library's `vec!`.
+///
+/// ```
+/// use minivec::{minivec, MiniVec};
+///
+/// let vector: MiniVec<i32> = minivec![1, 2, 3];
+/// assert_eq!(vector.size(), 3);
+/// assert_eq!(vector[0], 1);
+///
+/// let repeated: MiniVec<u8> = minivec![0; 5];
+/// assert_eq!(repeated.size(), 5);
+/// ```
+///
+/// The repeat form `minivec![elem; n]` expands to [`MiniVec::from_elem`].
+#[macro_export]
+macro_rules! minivec {
+    () => {
+        $crate::MiniVec::new()
+    };
+    ($elem:expr; $n:expr) => {
+        $crate::MiniVec::from_elem($elem, $n)
+    };
+    ($($x:expr),+ $(,)?) => {{
+        let mut vector = $crate::MiniVec::new();
+        $(vector.push($x);)+
+        vector
+    }};
+}
+
+pub struct MiniVec<T, A: Allocator = Global> {
     capacity: usize,
     size: usize,
     buffer: Option<*mut T>,
+    allocator: A,
 }
 
-impl<T: Clone> Drop for MiniVec<T> {
+impl<T, A: Allocator> Drop for MiniVec<T, A> {
     fn drop(&mut self) {
         if let Some(v) = self.buffer {
+            for i in 0..self.size {
+                unsafe { std::ptr::drop_in_place(v.add(i)) };
+            }
+
             let layout = alloc::Layout::array::<T>(self.capacity).unwrap();
-            unsafe { alloc::dealloc(v as *mut u8, layout) }
+            let ptr = unsafe { NonNull::new_unchecked(v as *mut u8) };
+            self.allocator.deallocate(ptr, layout);
         }
     }
 }
 
+/// The error returned by the fallible allocation APIs (`try_reserve`, `try_push`)
+/// when the requested capacity cannot be satisfied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// The requested capacity exceeds `isize::MAX` bytes, or the arithmetic
+    /// needed to compute it overflowed `usize`.
+    CapacityOverflow,
+    /// The [`Allocator`] was unable to satisfy a request for the given
+    /// `layout`.
+    AllocError { layout: alloc::Layout },
+}
+
 // TODO: Add more linting/formatting (https://dev.to/bampeers/rust-ci-with-github-actions-1ne9)
 // TODO: find idiomatic way of adding todos
 // TODO: create branches: dev, release and the feature branches
@@ -47,11 +149,8 @@ impl<T: Clone> Drop for MiniVec<T> {
 // TODO: add integration-tests
 // TODO: figure out how to do memory-leakage testing (valgrind?)
 
-impl<T: Clone> MiniVec<T> {
-    const DEFAULT_GROWTH: usize = 2;
-    const DEFAULT_FIRST_CAP: usize = 2;
-
-    /// creates a new instance of `MiniVec`.
+impl<T> MiniVec<T> {
+    /// creates a new instance of `MiniVec`, using the global allocator.
     ///
     /// ```
     /// use minivec::MiniVec;
@@ -62,11 +161,222 @@ impl<T: Clone> MiniVec<T> {
     /// Upon creation, the vector will have no heap allocation associated with it.
     /// The vector only allocates memory if it actually holds elements.
     pub fn new() -> Self {
+        Self::new_in(Global)
+    }
+
+    /// creates a new instance of `MiniVec` with enough capacity pre-allocated
+    /// to hold `n` elements without reallocating, using the global allocator.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let vector = MiniVec::<usize>::with_capacity(10);
+    /// assert_eq!(vector.capacity(), 10);
+    /// assert_eq!(vector.size(), 0);
+    /// ```
+    ///
+    /// Unlike `push`, which grows by repeated doubling, this performs a
+    /// single upfront allocation.
+    pub fn with_capacity(n: usize) -> Self {
+        Self::with_capacity_in(n, Global)
+    }
+
+    /// Creates a new `MiniVec` containing `n` clones of `elem`, using the
+    /// global allocator.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let vector = MiniVec::from_elem(0u8, 5);
+    /// assert_eq!(vector.size(), 5);
+    /// assert_eq!(vector[0], 0);
+    /// ```
+    ///
+    /// This is what the [`minivec!`](crate::minivec) repeat form
+    /// (`minivec![elem; n]`) expands to. When `elem`'s bit pattern is all
+    /// zero, as reported by an internal [`IsZero`] check covering the
+    /// primitive integer, float and `bool` types, this skips the
+    /// per-element clone loop and allocates the buffer directly with
+    /// `alloc::alloc_zeroed`, mirroring the `is_zero`/`SpecFromElem`
+    /// specialization `std::vec::from_elem` uses internally.
+    ///
+    /// Unlike `std::vec::from_elem`, this requires `T: 'static` — see
+    /// [`is_zero`] for why stable Rust needs that bound here.
+    ///
+    /// The backlog request that introduced this fast path also asked for a
+    /// benchmark comparing it against the naive clone loop for
+    /// `minivec![0u8; 1_000_000]`. That part was descoped: this crate has
+    /// no `Cargo.toml`/`benches/` harness (no dependency like `criterion`
+    /// can be declared without one), so there is nowhere to wire a
+    /// benchmark in without committing a manifest.
+    pub fn from_elem(elem: T, n: usize) -> Self
+    where
+        T: Clone + 'static,
+    {
+        if n == 0 {
+            return Self::new();
+        }
+
+        if is_zero(&elem) {
+            return Self::zeroed(n);
+        }
+
+        let mut vector = Self::with_capacity(n);
+        for _ in 0..n {
+            vector.push(elem.clone());
+        }
+        vector
+    }
+
+    /// Allocates `n` zeroed slots directly via `alloc::alloc_zeroed`,
+    /// without running any clones or constructors.
+    ///
+    /// Only correct to call when the all-zero bit pattern is a valid value
+    /// of `T`; callers establish this via [`IsZero`] before reaching here.
+    fn zeroed(n: usize) -> Self {
+        let layout = alloc::Layout::array::<T>(n).expect("capacity overflow");
+        let ptr = unsafe { alloc::alloc_zeroed(layout) };
+        let ptr = NonNull::new(ptr).expect("allocation failed");
+
+        Self {
+            capacity: n,
+            size: n,
+            buffer: Some(ptr.as_ptr() as *mut T),
+            allocator: Global,
+        }
+    }
+}
+
+/// Sealed marker trait implemented only for the primitive integer, float
+/// and `bool` types whose all-zero bit pattern is a valid value, used by
+/// [`MiniVec::from_elem`] to take a faster `alloc_zeroed` path instead of
+/// cloning the element into every slot.
+///
+/// Mirrors the `is_zero`/`SpecFromElem` specialization `std::vec::from_elem`
+/// uses internally, implemented here via `Any` downcasting since stable
+/// Rust has no specialization. See [`is_zero`] for why this requires
+/// `T: 'static`.
+trait IsZero {
+    fn is_zero(&self) -> bool;
+}
+
+macro_rules! impl_is_zero_int {
+    ($($ty:ty),*) => {
+        $(
+            impl IsZero for $ty {
+                fn is_zero(&self) -> bool {
+                    *self == 0
+                }
+            }
+        )*
+    };
+}
+
+impl_is_zero_int!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl IsZero for bool {
+    fn is_zero(&self) -> bool {
+        !*self
+    }
+}
+
+impl IsZero for f32 {
+    fn is_zero(&self) -> bool {
+        self.to_bits() == 0
+    }
+}
+
+impl IsZero for f64 {
+    fn is_zero(&self) -> bool {
+        self.to_bits() == 0
+    }
+}
+
+/// Reports whether `value`'s bit pattern is entirely zero, for types with
+/// a known [`IsZero`] impl; conservatively reports `false` otherwise.
+///
+/// A generic function's trait-method resolution is fixed once from its
+/// declared bounds, not re-decided per monomorphization, so an autoref- or
+/// method-resolution-based specialization trick can't conditionally reach
+/// the `IsZero` impl for a plain `T` here — every instantiation would
+/// resolve to the same (fallback) impl. `Any::downcast_ref` sidesteps that
+/// by comparing `TypeId`s, a check that *is* re-evaluated per concrete `T`,
+/// at the cost of requiring `T: 'static`.
+fn is_zero<T: 'static>(value: &T) -> bool {
+    use std::any::Any;
+
+    macro_rules! check {
+        ($($ty:ty),*) => {
+            $(
+                if let Some(v) = (value as &dyn Any).downcast_ref::<$ty>() {
+                    return v.is_zero();
+                }
+            )*
+        };
+    }
+
+    check!(
+        u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, bool, f32, f64
+    );
+
+    false
+}
+
+impl<T, A: Allocator> MiniVec<T, A> {
+    const DEFAULT_GROWTH: usize = 2;
+    const DEFAULT_FIRST_CAP: usize = 2;
+
+    /// creates a new instance of `MiniVec` backed by `allocator`.
+    ///
+    /// ```
+    /// use minivec::{Global, MiniVec};
+    ///
+    /// let vector = MiniVec::<usize, Global>::new_in(Global);
+    /// ```
+    ///
+    /// Upon creation, the vector will have no heap allocation associated with it.
+    /// The vector only allocates memory if it actually holds elements.
+    pub fn new_in(allocator: A) -> Self {
         Self {
             capacity: 0,
             size: 0,
             buffer: None,
+            allocator,
+        }
+    }
+
+    /// creates a new instance of `MiniVec` backed by `allocator`, with
+    /// enough capacity pre-allocated to hold `n` elements without
+    /// reallocating.
+    ///
+    /// ```
+    /// use minivec::{Global, MiniVec};
+    ///
+    /// let vector = MiniVec::<usize, Global>::with_capacity_in(10, Global);
+    /// assert_eq!(vector.capacity(), 10);
+    /// assert_eq!(vector.size(), 0);
+    /// ```
+    ///
+    /// Unlike the growth performed by `push`/`try_reserve`, which doubles
+    /// the capacity each time it is exceeded, this performs a single
+    /// upfront allocation of exactly `n` elements.
+    pub fn with_capacity_in(n: usize, allocator: A) -> Self {
+        let mut vector = Self::new_in(allocator);
+
+        if n == 0 {
+            return vector;
         }
+
+        let layout = alloc::Layout::array::<T>(n).expect("capacity overflow");
+        let ptr = vector
+            .allocator
+            .allocate(layout)
+            .expect("allocation failed");
+
+        vector.buffer = Some(ptr.as_ptr() as *mut T);
+        vector.capacity = n;
+
+        vector
     }
 
     /// returns number of elements in this collection.
@@ -109,50 +419,130 @@ impl<T: Clone> MiniVec<T> {
         self.capacity
     }
 
-    fn grow(&mut self) {
-        match self.buffer {
+    /// Reserves capacity for at least `additional` more elements, growing
+    /// the buffer by repeated doubling if necessary.
+    ///
+    /// Unlike `push`, which panics when growing the buffer fails, this returns
+    /// a [`TryReserveError`] when the capacity computation overflows or the
+    /// allocator fails, following the fallible-allocation
+    /// approach used by `rust-for-linux`.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let mut vector = MiniVec::<i32>::new();
+    /// vector.try_reserve(10).unwrap();
+    /// assert!(vector.capacity() >= 10);
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let required = self
+            .size
+            .checked_add(additional)
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        if required <= self.capacity {
+            return Ok(());
+        }
+
+        let mut new_capacity = if self.capacity == 0 {
+            Self::DEFAULT_FIRST_CAP
+        } else {
+            self.capacity
+        };
+
+        while new_capacity < required {
+            new_capacity = new_capacity
+                .checked_mul(Self::DEFAULT_GROWTH)
+                .ok_or(TryReserveError::CapacityOverflow)?;
+        }
+
+        let new_byte_size = new_capacity
+            .checked_mul(std::mem::size_of::<T>())
+            .ok_or(TryReserveError::CapacityOverflow)?;
+
+        if new_byte_size > isize::MAX as usize {
+            return Err(TryReserveError::CapacityOverflow);
+        }
+
+        let new_layout =
+            alloc::Layout::array::<T>(new_capacity).map_err(|_| TryReserveError::CapacityOverflow)?;
+
+        let new_buffer = match self.buffer {
             Some(v) => {
-                let layout = alloc::Layout::array::<T>(self.capacity).unwrap();
-                self.capacity *= MiniVec::<T>::DEFAULT_GROWTH;
-                self.buffer = Some(unsafe {
-                    alloc::realloc(
-                        v as *mut u8,
-                        layout,
-                        self.capacity * std::mem::size_of::<T>(),
-                    ) as *mut T
-                });
-                // TODO: check for self.buffer to be null here...
-            }
-            None => {
-                self.capacity = MiniVec::<T>::DEFAULT_FIRST_CAP;
-                let layout = alloc::Layout::array::<T>(self.capacity).unwrap();
-                self.buffer = Some(unsafe { alloc::alloc(layout) as *mut T });
+                let old_layout = alloc::Layout::array::<T>(self.capacity).unwrap();
+                let old_ptr = unsafe { NonNull::new_unchecked(v as *mut u8) };
+                self.allocator.grow(old_ptr, old_layout, new_layout)
             }
+            None => self.allocator.allocate(new_layout),
         }
+        .map_err(|_| TryReserveError::AllocError { layout: new_layout })?;
+
+        self.buffer = Some(new_buffer.as_ptr() as *mut T);
+        self.capacity = new_capacity;
+
+        Ok(())
     }
 
-    /// Add an element to the end of the vector.
+    /// Reserves capacity for at least `additional` more elements.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let mut vector = MiniVec::<i32>::new();
+    /// vector.reserve(10);
+    /// assert!(vector.capacity() >= 10);
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocation fails. Use
+    /// [`try_reserve`](MiniVec::try_reserve) to handle this case instead.
+    pub fn reserve(&mut self, additional: usize) {
+        self.try_reserve(additional).expect("allocation failed");
+    }
+
+    /// Fallible version of [`push`](MiniVec::push) that returns a
+    /// [`TryReserveError`] instead of panicking when growing the buffer fails.
     ///
     /// ```
     /// use minivec::MiniVec;
     ///
     /// let mut vector = MiniVec::new();
-    /// vector.push(0);
+    /// vector.try_push(0).unwrap();
     /// assert_eq!(vector[0], 0);
     /// ```
-    ///
-    /// This method is O(1). If the size of the vector is the capacity,
-    /// it will reallocate the buffer.
-    pub fn push(&mut self, new_element: T) {
+    pub fn try_push(&mut self, new_element: T) -> Result<(), TryReserveError> {
         if self.size == self.capacity {
-            self.grow();
+            self.try_reserve(1)?;
         }
 
-        // TODO: is already checked in grow... unnecessary (maybe use NonNull pointer?)
         unsafe {
-            *(self.buffer.unwrap().offset(self.size as isize)) = new_element;
+            std::ptr::write(self.buffer.unwrap().add(self.size), new_element);
         }
         self.size += 1;
+
+        Ok(())
+    }
+
+    /// Add an element to the end of the vector.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let mut vector = MiniVec::new();
+    /// vector.push(0);
+    /// assert_eq!(vector[0], 0);
+    /// ```
+    ///
+    /// This method is O(1). If the size of the vector is the capacity,
+    /// it will reallocate the buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the allocation fails. Use [`try_push`](MiniVec::try_push)
+    /// to handle this case instead.
+    pub fn push(&mut self, new_element: T) {
+        self.try_push(new_element).expect("allocation failed");
     }
 
     /// Remove a element on the given position, returns it.
@@ -165,64 +555,548 @@ impl<T: Clone> MiniVec<T> {
     /// assert_eq!(vector.remove(0), 0);
     /// ```
     /// This has a worst-case runtime of O(n).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
     pub fn remove(&mut self, index: usize) -> T {
-        let removed = unsafe { (*(self.buffer.unwrap().offset(index as isize))).clone() };
+        if index >= self.size {
+            panic!("Error: Index too big for vector");
+        }
+
+        let base = self.buffer.unwrap();
 
         unsafe {
-            let mut start = self.buffer.unwrap().offset(index as isize);
+            let slot = base.add(index);
+            let removed = std::ptr::read(slot);
 
-            for _ in index..self.size - 1 {
-                *start = (*(start.offset(1) as *mut T)).clone();
-                start = start.offset(1);
-            }
+            let tail_len = self.size - index - 1;
+            std::ptr::copy(slot.add(1), slot, tail_len);
+
+            self.size -= 1;
+
+            removed
+        }
+    }
+
+    /// Removes and returns the last element, or `None` if the vector is empty.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let mut vector = MiniVec::new();
+    /// vector.push(0);
+    ///
+    /// assert_eq!(vector.pop(), Some(0));
+    /// assert_eq!(vector.pop(), None);
+    /// ```
+    pub fn pop(&mut self) -> Option<T> {
+        if self.size == 0 {
+            return None;
         }
 
         self.size -= 1;
 
-        removed
+        unsafe { Some(std::ptr::read(self.buffer.unwrap().add(self.size))) }
     }
-}
 
-impl<T: Clone> Index<usize> for MiniVec<T> {
-    type Output = T;
+    /// Returns the raw pointer to the first element, or a dangling but
+    /// well-aligned pointer if the vector has not allocated yet.
+    fn data_ptr(&self) -> *mut T {
+        self.buffer.unwrap_or_else(|| NonNull::dangling().as_ptr())
+    }
 
-    fn index(&self, index: usize) -> &T {
-        if index >= self.size {
-            panic!("Error: Index too big for vector");
+    /// Returns an iterator over references to the elements.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let mut vector = MiniVec::new();
+    /// vector.push(1);
+    /// vector.push(2);
+    ///
+    /// let sum: i32 = vector.iter().sum();
+    /// assert_eq!(sum, 3);
+    /// ```
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        unsafe { std::slice::from_raw_parts(self.data_ptr(), self.size).iter() }
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let mut vector = MiniVec::new();
+    /// vector.push(1);
+    /// vector.push(2);
+    ///
+    /// for x in vector.iter_mut() {
+    ///     *x += 10;
+    /// }
+    /// assert_eq!(vector[0], 11);
+    /// assert_eq!(vector[1], 12);
+    /// ```
+    pub fn iter_mut(&mut self) -> std::slice::IterMut<'_, T> {
+        unsafe { std::slice::from_raw_parts_mut(self.data_ptr(), self.size).iter_mut() }
+    }
+
+    /// Keeps only the elements for which `f` returns `true`, dropping the
+    /// rest in place.
+    ///
+    /// This is a single in-place compaction pass: kept elements are shifted
+    /// down with `ptr::copy` as needed, and dropped elements are dropped
+    /// exactly once via `ptr::drop_in_place`.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let mut vector = MiniVec::new();
+    /// vector.push(1);
+    /// vector.push(2);
+    /// vector.push(3);
+    /// vector.push(4);
+    ///
+    /// vector.retain(|x| x % 2 == 0);
+    ///
+    /// assert_eq!(vector.size(), 2);
+    /// assert_eq!(vector[0], 2);
+    /// assert_eq!(vector[1], 4);
+    /// ```
+    ///
+    /// If `f` panics partway through, the elements not yet examined are
+    /// treated as kept: the [`RetainGuard`] backing this method shifts them
+    /// down over the already-dropped elements and repairs `self`'s length
+    /// during unwinding, so `self` is never left claiming a slot that was
+    /// already dropped or duplicated by the compaction.
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        let original_len = self.size;
+        let base = self.data_ptr();
+        self.size = 0;
+
+        let mut guard = RetainGuard {
+            vec: self,
+            original_len,
+            processed_len: 0,
+            deleted_cnt: 0,
+        };
+
+        while guard.processed_len < original_len {
+            unsafe {
+                let slot = base.add(guard.processed_len);
+
+                if f(&*slot) {
+                    if guard.deleted_cnt > 0 {
+                        std::ptr::copy(slot, base.add(guard.processed_len - guard.deleted_cnt), 1);
+                    }
+                    guard.processed_len += 1;
+                } else {
+                    guard.processed_len += 1;
+                    guard.deleted_cnt += 1;
+                    std::ptr::drop_in_place(slot);
+                }
+            }
         }
+    }
 
-        unsafe { &*(self.buffer.unwrap().offset(index as isize)) }
+    /// Removes and returns every element for which `f` returns `true`,
+    /// compacting the remaining elements down in place.
+    ///
+    /// The returned [`ExtractIf`] is lazy: it extracts the next matching
+    /// element on each call to `next`. Dropping it before it is exhausted
+    /// still finishes scanning and compacting the rest of the vector, so
+    /// `self` is always left valid even if the iterator is abandoned early.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let mut vector = MiniVec::new();
+    /// vector.push(1);
+    /// vector.push(2);
+    /// vector.push(3);
+    /// vector.push(4);
+    ///
+    /// let evens: Vec<i32> = vector.extract_if(|x| *x % 2 == 0).collect();
+    ///
+    /// assert_eq!(evens, vec![2, 4]);
+    /// assert_eq!(vector.size(), 2);
+    /// assert_eq!(vector[0], 1);
+    /// assert_eq!(vector[1], 3);
+    /// ```
+    pub fn extract_if<F>(&mut self, f: F) -> ExtractIf<'_, T, A, F>
+    where
+        F: FnMut(&mut T) -> bool,
+    {
+        let original_len = self.size;
+        self.size = 0;
+
+        ExtractIf {
+            vec: self,
+            original_len,
+            read: 0,
+            write: 0,
+            pred: f,
+        }
     }
 }
 
-impl<T: Clone> IndexMut<usize> for MiniVec<T> {
-    fn index_mut(&mut self, index: usize) -> &mut T {
-        if index >= self.size {
-            panic!("Error: Index too big for vector");
+/// Backshift-on-drop guard for [`MiniVec::retain`], mirroring `std`'s
+/// `BackshiftOnDrop`.
+///
+/// While `retain` is running, `vec`'s length is held at `0` and this guard
+/// tracks how far the scan has gotten (`processed_len`) and how many
+/// elements it has already dropped (`deleted_cnt`). On drop — whether
+/// `retain` finished normally or `f` panicked and unwound through it — any
+/// elements from `processed_len` onward that were never examined are still
+/// fully valid, so they are shifted down over the hole left by the deleted
+/// elements and `vec`'s length is restored to `original_len - deleted_cnt`.
+/// This ensures `vec` never claims a slot that was already dropped or
+/// overwritten by the compaction.
+struct RetainGuard<'a, T, A: Allocator> {
+    vec: &'a mut MiniVec<T, A>,
+    original_len: usize,
+    processed_len: usize,
+    deleted_cnt: usize,
+}
+
+impl<T, A: Allocator> Drop for RetainGuard<'_, T, A> {
+    fn drop(&mut self) {
+        if self.deleted_cnt > 0 {
+            let base = self.vec.data_ptr();
+            let unprocessed = self.original_len - self.processed_len;
+
+            unsafe {
+                std::ptr::copy(
+                    base.add(self.processed_len),
+                    base.add(self.processed_len - self.deleted_cnt),
+                    unprocessed,
+                );
+            }
         }
 
-        unsafe { &mut *(self.buffer.unwrap().offset(index as isize)) }
+        self.vec.size = self.original_len - self.deleted_cnt;
     }
 }
 
-#[cfg(test)]
-mod tests {
+/// Lazy iterator returned by [`MiniVec::extract_if`].
+///
+/// Yields each element for which the predicate returned `true`, compacting
+/// the vector as it goes. If dropped before exhaustion, it finishes
+/// compacting the remaining elements so the originating vector is left in a
+/// valid, fully-compacted state.
+pub struct ExtractIf<'a, T, A: Allocator, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    vec: &'a mut MiniVec<T, A>,
+    original_len: usize,
+    read: usize,
+    write: usize,
+    pred: F,
+}
 
-    use super::*;
+impl<T, A: Allocator, F> Iterator for ExtractIf<'_, T, A, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    type Item = T;
 
-    #[test]
-    fn new() {
-        let vector = MiniVec::<i32>::new();
-        assert_eq!(vector.size(), 0);
-        assert_eq!(vector.capacity(), 0);
-    }
+    fn next(&mut self) -> Option<T> {
+        let base = self.vec.data_ptr();
 
-    #[test]
-    fn add_element() {
-        let mut vector = MiniVec::new();
+        while self.read < self.original_len {
+            unsafe {
+                let slot = base.add(self.read);
 
-        vector.push(1);
-        assert_eq!(vector.size(), 1);
+                if (self.pred)(&mut *slot) {
+                    self.read += 1;
+                    return Some(std::ptr::read(slot));
+                }
+
+                if self.read != self.write {
+                    std::ptr::copy(slot, base.add(self.write), 1);
+                }
+                self.read += 1;
+                self.write += 1;
+            }
+        }
+
+        None
+    }
+}
+
+impl<T, A: Allocator, F> Drop for ExtractIf<'_, T, A, F>
+where
+    F: FnMut(&mut T) -> bool,
+{
+    fn drop(&mut self) {
+        let base = self.vec.data_ptr();
+
+        while self.read < self.original_len {
+            unsafe {
+                let slot = base.add(self.read);
+
+                if (self.pred)(&mut *slot) {
+                    std::ptr::drop_in_place(slot);
+                } else {
+                    if self.read != self.write {
+                        std::ptr::copy(slot, base.add(self.write), 1);
+                    }
+                    self.write += 1;
+                }
+            }
+            self.read += 1;
+        }
+
+        self.vec.size = self.write;
+    }
+}
+
+/// An owning iterator over the elements of a [`MiniVec`], obtained via
+/// [`IntoIterator::into_iter`].
+///
+/// Mirrors `std`'s `vec::IntoIter`: it holds on to the original buffer so it
+/// can deallocate it on drop, and drops any elements that were never
+/// consumed, so abandoning a partially-consumed `IntoIter` neither leaks nor
+/// double-drops.
+pub struct IntoIter<T, A: Allocator = Global> {
+    buffer: Option<*mut T>,
+    capacity: usize,
+    start: *mut T,
+    end: *mut T,
+    allocator: A,
+}
+
+impl<T, A: Allocator> Iterator for IntoIter<T, A> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+
+        unsafe {
+            let element = std::ptr::read(self.start);
+            self.start = self.start.add(1);
+            Some(element)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+
+impl<T, A: Allocator> DoubleEndedIterator for IntoIter<T, A> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start == self.end {
+            return None;
+        }
+
+        unsafe {
+            self.end = self.end.sub(1);
+            Some(std::ptr::read(self.end))
+        }
+    }
+}
+
+impl<T, A: Allocator> ExactSizeIterator for IntoIter<T, A> {
+    fn len(&self) -> usize {
+        unsafe { self.end.offset_from(self.start) as usize }
+    }
+}
+
+impl<T, A: Allocator> Drop for IntoIter<T, A> {
+    fn drop(&mut self) {
+        unsafe {
+            std::ptr::drop_in_place(std::ptr::slice_from_raw_parts_mut(self.start, self.len()));
+        }
+
+        if let Some(v) = self.buffer {
+            let layout = alloc::Layout::array::<T>(self.capacity).unwrap();
+            let ptr = unsafe { NonNull::new_unchecked(v as *mut u8) };
+            self.allocator.deallocate(ptr, layout);
+        }
+    }
+}
+
+impl<T, A: Allocator> IntoIterator for MiniVec<T, A> {
+    type Item = T;
+    type IntoIter = IntoIter<T, A>;
+
+    /// Converts the vector into an owning iterator, taking ownership of
+    /// every element.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let mut vector = MiniVec::new();
+    /// vector.push(1);
+    /// vector.push(2);
+    ///
+    /// let collected: Vec<i32> = vector.into_iter().collect();
+    /// assert_eq!(collected, vec![1, 2]);
+    /// ```
+    fn into_iter(self) -> IntoIter<T, A> {
+        let me = std::mem::ManuallyDrop::new(self);
+
+        let buffer = me.buffer;
+        let start = me.data_ptr();
+        let end = unsafe { start.add(me.size) };
+        let allocator = unsafe { std::ptr::read(&me.allocator) };
+
+        IntoIter {
+            buffer,
+            capacity: me.capacity,
+            start,
+            end,
+            allocator,
+        }
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a MiniVec<T, A> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl<'a, T, A: Allocator> IntoIterator for &'a mut MiniVec<T, A> {
+    type Item = &'a mut T;
+    type IntoIter = std::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter_mut()
+    }
+}
+
+impl<T: Copy, A: Allocator> MiniVec<T, A> {
+    /// Appends every element of `slice` to the end of the vector, copying
+    /// them in bulk with `ptr::copy_nonoverlapping` after a single
+    /// reservation, rather than pushing one at a time.
+    ///
+    /// This is a distinct, explicit method rather than something
+    /// [`extend`](Extend::extend)/[`from_iter`](FromIterator::from_iter)
+    /// dispatch into automatically — see [`is_zero`] for why stable Rust
+    /// can't make that dispatch conditional on the source type. Callers who
+    /// already hold a slice should call this directly instead of relying on
+    /// `extend` to find it for them.
+    ///
+    /// ```
+    /// use minivec::MiniVec;
+    ///
+    /// let mut vector = MiniVec::new();
+    /// vector.push(1);
+    /// vector.extend_from_slice(&[2, 3, 4]);
+    ///
+    /// assert_eq!(vector.size(), 4);
+    /// assert_eq!(vector[3], 4);
+    /// ```
+    pub fn extend_from_slice(&mut self, slice: &[T]) {
+        if slice.is_empty() {
+            return;
+        }
+
+        self.reserve(slice.len());
+
+        unsafe {
+            let dst = self.data_ptr().add(self.size);
+            std::ptr::copy_nonoverlapping(slice.as_ptr(), dst, slice.len());
+        }
+
+        self.size += slice.len();
+    }
+}
+
+/// Extends the vector with the contents of an iterator.
+///
+/// Following `std`'s `spec_extend`, this reads the iterator's
+/// `size_hint().0` and reserves that capacity once up front via
+/// [`reserve`](MiniVec::reserve), rather than growing element-by-element.
+/// Every element is still pushed one at a time after that single
+/// reservation — this impl does not detect and bulk-copy `Copy`/slice
+/// sources (see [`is_zero`] for why stable Rust can't specialize this kind
+/// of call per source type). Callers that already hold a `&[T]` of `Copy`
+/// elements and want the O(1)-allocations bulk copy should call
+/// [`extend_from_slice`](MiniVec::extend_from_slice) directly instead of
+/// going through `extend`.
+///
+/// This is a partial implementation of the backlog request that introduced
+/// this impl, which asked for `extend`/`from_iter` themselves to detect and
+/// bulk-copy `Copy`/slice sources; that part was descoped for the reason
+/// above, leaving [`extend_from_slice`](MiniVec::extend_from_slice) as a
+/// separate opt-in method instead.
+impl<T, A: Allocator> Extend<T> for MiniVec<T, A> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+
+        let (lower, _) = iter.size_hint();
+        if lower > 0 {
+            self.reserve(lower);
+        }
+
+        for element in iter {
+            self.push(element);
+        }
+    }
+}
+
+/// Builds a [`MiniVec`] from an iterator, e.g. via `Iterator::collect`.
+impl<T> FromIterator<T> for MiniVec<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vector = MiniVec::new();
+        vector.extend(iter);
+        vector
+    }
+}
+
+impl<T, A: Allocator> Index<usize> for MiniVec<T, A> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        if index >= self.size {
+            panic!("Error: Index too big for vector");
+        }
+
+        unsafe { &*(self.buffer.unwrap().offset(index as isize)) }
+    }
+}
+
+impl<T, A: Allocator> IndexMut<usize> for MiniVec<T, A> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        if index >= self.size {
+            panic!("Error: Index too big for vector");
+        }
+
+        unsafe { &mut *(self.buffer.unwrap().offset(index as isize)) }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+
+    use super::*;
+
+    #[test]
+    fn new() {
+        let vector = MiniVec::<i32>::new();
+        assert_eq!(vector.size(), 0);
+        assert_eq!(vector.capacity(), 0);
+    }
+
+    #[test]
+    fn add_element() {
+        let mut vector = MiniVec::new();
+
+        vector.push(1);
+        assert_eq!(vector.size(), 1);
 
         vector.push(2);
         assert_eq!(vector.size(), 2);
@@ -285,6 +1159,17 @@ mod tests {
         vector.remove(0);
     }
 
+    #[test]
+    #[should_panic]
+    fn remove_element_by_index_out_of_range_on_nonempty_vector() {
+        let mut vector = MiniVec::new();
+
+        vector.push(1);
+        vector.push(2);
+
+        vector.remove(5);
+    }
+
     #[test]
     fn correct_realloc_call() {
         let mut vector = MiniVec::<i32>::new();
@@ -326,4 +1211,458 @@ mod tests {
 
         vector[1000] = 30;
     }
+
+    #[test]
+    fn try_reserve_grows_capacity() {
+        let mut vector = MiniVec::<i32>::new();
+
+        vector.try_reserve(10).unwrap();
+        assert!(vector.capacity() >= 10);
+        assert_eq!(vector.size(), 0);
+    }
+
+    #[test]
+    fn try_reserve_capacity_overflow() {
+        let mut vector = MiniVec::<i32>::new();
+
+        let result = vector.try_reserve(usize::MAX);
+        assert_eq!(result, Err(TryReserveError::CapacityOverflow));
+    }
+
+    #[test]
+    fn try_push_valid() {
+        let mut vector = MiniVec::new();
+
+        vector.try_push(1).unwrap();
+        vector.try_push(2).unwrap();
+
+        assert_eq!(vector.size(), 2);
+        assert_eq!(vector[0], 1);
+        assert_eq!(vector[1], 2);
+    }
+
+    #[test]
+    fn pop_element() {
+        let mut vector = MiniVec::new();
+
+        vector.push(1);
+        vector.push(2);
+
+        assert_eq!(vector.pop(), Some(2));
+        assert_eq!(vector.size(), 1);
+        assert_eq!(vector.pop(), Some(1));
+        assert_eq!(vector.size(), 0);
+        assert_eq!(vector.pop(), None);
+    }
+
+    struct DropCounter<'a> {
+        count: &'a std::cell::RefCell<usize>,
+    }
+
+    impl Drop for DropCounter<'_> {
+        fn drop(&mut self) {
+            *self.count.borrow_mut() += 1;
+        }
+    }
+
+    #[test]
+    fn drop_runs_exactly_once_per_element() {
+        let count = std::cell::RefCell::new(0);
+
+        {
+            let mut vector = MiniVec::new();
+
+            for _ in 0..5 {
+                vector.push(DropCounter { count: &count });
+            }
+        }
+
+        assert_eq!(*count.borrow(), 5);
+    }
+
+    #[test]
+    fn remove_drops_only_removed_element() {
+        let count = std::cell::RefCell::new(0);
+
+        {
+            let mut vector = MiniVec::new();
+
+            for _ in 0..3 {
+                vector.push(DropCounter { count: &count });
+            }
+
+            let removed = vector.remove(1);
+            assert_eq!(*count.borrow(), 0);
+            drop(removed);
+            assert_eq!(*count.borrow(), 1);
+        }
+
+        assert_eq!(*count.borrow(), 3);
+    }
+
+    #[test]
+    fn pop_drops_only_popped_element() {
+        let count = std::cell::RefCell::new(0);
+
+        {
+            let mut vector = MiniVec::new();
+
+            for _ in 0..3 {
+                vector.push(DropCounter { count: &count });
+            }
+
+            let popped = vector.pop().unwrap();
+            assert_eq!(*count.borrow(), 0);
+            drop(popped);
+            assert_eq!(*count.borrow(), 1);
+        }
+
+        assert_eq!(*count.borrow(), 3);
+    }
+
+    #[test]
+    fn iter_yields_references() {
+        let mut vector = MiniVec::new();
+        vector.push(1);
+        vector.push(2);
+        vector.push(3);
+
+        let collected: Vec<&i32> = vector.iter().collect();
+        assert_eq!(collected, vec![&1, &2, &3]);
+    }
+
+    #[test]
+    fn iter_mut_allows_mutation() {
+        let mut vector = MiniVec::new();
+        vector.push(1);
+        vector.push(2);
+
+        for x in vector.iter_mut() {
+            *x *= 10;
+        }
+
+        assert_eq!(vector[0], 10);
+        assert_eq!(vector[1], 20);
+    }
+
+    #[test]
+    fn for_loop_over_reference() {
+        let mut vector = MiniVec::new();
+        vector.push(1);
+        vector.push(2);
+
+        let mut sum = 0;
+        for x in &vector {
+            sum += x;
+        }
+        assert_eq!(sum, 3);
+    }
+
+    #[test]
+    fn into_iter_collects_in_order() {
+        let mut vector = MiniVec::new();
+        vector.push(1);
+        vector.push(2);
+        vector.push(3);
+
+        let collected: Vec<i32> = vector.into_iter().collect();
+        assert_eq!(collected, vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn into_iter_is_double_ended() {
+        let mut vector = MiniVec::new();
+        vector.push(1);
+        vector.push(2);
+        vector.push(3);
+
+        let mut into_iter = vector.into_iter();
+        assert_eq!(into_iter.next(), Some(1));
+        assert_eq!(into_iter.next_back(), Some(3));
+        assert_eq!(into_iter.next(), Some(2));
+        assert_eq!(into_iter.next(), None);
+    }
+
+    #[test]
+    fn into_iter_drops_unconsumed_elements() {
+        let count = std::cell::RefCell::new(0);
+
+        {
+            let mut vector = MiniVec::new();
+            for _ in 0..4 {
+                vector.push(DropCounter { count: &count });
+            }
+
+            let mut into_iter = vector.into_iter();
+            into_iter.next();
+            into_iter.next();
+            // the remaining two elements are dropped when `into_iter` goes
+            // out of scope here.
+        }
+
+        assert_eq!(*count.borrow(), 4);
+    }
+
+    #[test]
+    fn retain_keeps_matching_elements() {
+        let mut vector = MiniVec::new();
+        for i in 1..=6 {
+            vector.push(i);
+        }
+
+        vector.retain(|x| x % 2 == 0);
+
+        assert_eq!(vector.size(), 3);
+        assert_eq!(vector[0], 2);
+        assert_eq!(vector[1], 4);
+        assert_eq!(vector[2], 6);
+    }
+
+    #[test]
+    fn retain_drops_removed_elements() {
+        let count = std::cell::RefCell::new(0);
+
+        {
+            let mut vector = MiniVec::new();
+            for _ in 0..4 {
+                vector.push(DropCounter { count: &count });
+            }
+
+            let mut n = 0;
+            vector.retain(|_| {
+                n += 1;
+                n % 2 == 0
+            });
+
+            assert_eq!(vector.size(), 2);
+        }
+
+        assert_eq!(*count.borrow(), 4);
+    }
+
+    #[test]
+    fn retain_panic_mid_scan_does_not_double_drop() {
+        let count = std::cell::RefCell::new(0);
+
+        {
+            let mut vector = MiniVec::new();
+            for _ in 0..6 {
+                vector.push(DropCounter { count: &count });
+            }
+
+            let mut n = 0;
+            let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                vector.retain(|_| {
+                    n += 1;
+                    if n == 4 {
+                        panic!("boom");
+                    }
+                    n % 2 == 0
+                });
+            }));
+
+            assert!(result.is_err());
+            // `vector` goes out of scope here; its `Drop` must run exactly
+            // once per live element, whether or not `retain` finished.
+        }
+
+        assert_eq!(*count.borrow(), 6);
+    }
+
+    #[test]
+    fn extract_if_yields_matching_and_compacts_rest() {
+        let mut vector = MiniVec::new();
+        for i in 1..=6 {
+            vector.push(i);
+        }
+
+        let extracted: Vec<i32> = vector.extract_if(|x| *x % 2 == 0).collect();
+
+        assert_eq!(extracted, vec![2, 4, 6]);
+        assert_eq!(vector.size(), 3);
+        assert_eq!(vector[0], 1);
+        assert_eq!(vector[1], 3);
+        assert_eq!(vector[2], 5);
+    }
+
+    #[test]
+    fn extract_if_dropped_early_still_compacts() {
+        let mut vector = MiniVec::new();
+        for i in 1..=6 {
+            vector.push(i);
+        }
+
+        {
+            let mut iter = vector.extract_if(|x| *x % 2 == 0);
+            assert_eq!(iter.next(), Some(2));
+            // the remaining elements (3, 4, 5, 6) are scanned when `iter`
+            // is dropped here: the still-matching ones (4, 6) are dropped
+            // in place, without being returned, and the rest compacted.
+        }
+
+        assert_eq!(vector.size(), 3);
+        assert_eq!(vector[0], 1);
+        assert_eq!(vector[1], 3);
+        assert_eq!(vector[2], 5);
+    }
+
+    #[test]
+    fn extract_if_drops_unreturned_matches() {
+        let count = std::cell::RefCell::new(0);
+
+        {
+            let mut vector = MiniVec::new();
+            for _ in 0..4 {
+                vector.push(DropCounter { count: &count });
+            }
+
+            let mut iter = vector.extract_if(|_| true);
+            let first = iter.next().unwrap();
+            drop(first);
+            assert_eq!(*count.borrow(), 1);
+            // dropping `iter` here extracts-and-drops the remaining three
+            // matching elements.
+        }
+
+        assert_eq!(*count.borrow(), 4);
+    }
+
+    struct CountingAllocator {
+        allocations: std::cell::Cell<usize>,
+    }
+
+    impl Allocator for CountingAllocator {
+        fn allocate(&self, layout: alloc::Layout) -> Result<NonNull<u8>, AllocError> {
+            self.allocations.set(self.allocations.get() + 1);
+            Global.allocate(layout)
+        }
+
+        fn deallocate(&self, ptr: NonNull<u8>, layout: alloc::Layout) {
+            Global.deallocate(ptr, layout)
+        }
+
+        fn grow(
+            &self,
+            ptr: NonNull<u8>,
+            old_layout: alloc::Layout,
+            new_layout: alloc::Layout,
+        ) -> Result<NonNull<u8>, AllocError> {
+            self.allocations.set(self.allocations.get() + 1);
+            Global.grow(ptr, old_layout, new_layout)
+        }
+    }
+
+    #[test]
+    fn new_in_uses_the_given_allocator() {
+        let allocator = CountingAllocator {
+            allocations: std::cell::Cell::new(0),
+        };
+
+        let mut vector = MiniVec::new_in(allocator);
+        vector.push(1);
+        vector.push(2);
+        vector.push(3);
+
+        assert_eq!(vector.size(), 3);
+        assert!(vector.allocator.allocations.get() >= 1);
+    }
+
+    #[test]
+    fn with_capacity_preallocates_exactly() {
+        let vector = MiniVec::<i32>::with_capacity(10);
+        assert_eq!(vector.capacity(), 10);
+        assert_eq!(vector.size(), 0);
+    }
+
+    #[test]
+    fn with_capacity_zero_does_not_allocate() {
+        let vector = MiniVec::<i32>::with_capacity(0);
+        assert_eq!(vector.capacity(), 0);
+        assert!(vector.buffer.is_none());
+    }
+
+    #[test]
+    fn from_elem_clones_nonzero_value() {
+        let vector = MiniVec::from_elem("x".to_string(), 3);
+        assert_eq!(vector.size(), 3);
+        assert_eq!(vector[0], "x");
+        assert_eq!(vector[2], "x");
+    }
+
+    #[test]
+    fn from_elem_zero_takes_zeroed_fast_path() {
+        let vector = MiniVec::from_elem(0u8, 1000);
+        assert_eq!(vector.size(), 1000);
+        assert_eq!(vector.capacity(), 1000);
+        assert!(vector.iter().all(|&x| x == 0));
+    }
+
+    #[test]
+    fn minivec_macro_empty() {
+        let vector: MiniVec<i32> = minivec![];
+        assert_eq!(vector.size(), 0);
+    }
+
+    #[test]
+    fn minivec_macro_list_form() {
+        let vector: MiniVec<i32> = minivec![1, 2, 3];
+        assert_eq!(vector.size(), 3);
+        assert_eq!(vector[0], 1);
+        assert_eq!(vector[1], 2);
+        assert_eq!(vector[2], 3);
+    }
+
+    #[test]
+    fn minivec_macro_repeat_form() {
+        let vector: MiniVec<u8> = minivec![7; 4];
+        assert_eq!(vector.size(), 4);
+        assert!(vector.iter().all(|&x| x == 7));
+    }
+
+    #[test]
+    fn reserve_grows_capacity() {
+        let mut vector = MiniVec::<i32>::new();
+
+        vector.reserve(10);
+        assert!(vector.capacity() >= 10);
+        assert_eq!(vector.size(), 0);
+    }
+
+    #[test]
+    fn extend_from_slice_bulk_copies() {
+        let mut vector = MiniVec::new();
+        vector.push(1);
+
+        vector.extend_from_slice(&[2, 3, 4]);
+
+        assert_eq!(vector.size(), 4);
+        assert_eq!(vector[0], 1);
+        assert_eq!(vector[1], 2);
+        assert_eq!(vector[2], 3);
+        assert_eq!(vector[3], 4);
+    }
+
+    #[test]
+    fn extend_reserves_using_size_hint_and_pushes_remainder() {
+        let mut vector = MiniVec::new();
+        vector.push(0);
+
+        // size_hint().0 is 2 here: two elements are covered by the
+        // reservation and the third is pushed beyond the hint.
+        vector.extend(vec![1, 2].into_iter().chain((3..4).filter(|_| true)));
+
+        assert_eq!(vector.size(), 4);
+        assert!(vector.capacity() >= 4);
+        assert_eq!(vector[1], 1);
+        assert_eq!(vector[3], 3);
+    }
+
+    #[test]
+    fn from_iterator_collects_into_minivec() {
+        let vector: MiniVec<i32> = (1..=4).collect();
+
+        assert_eq!(vector.size(), 4);
+        assert_eq!(vector[0], 1);
+        assert_eq!(vector[3], 4);
+    }
 }